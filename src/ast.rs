@@ -0,0 +1,66 @@
+//! Abstract syntax tree produced by [`crate::parser::parse`] and walked by
+//! [`crate::interpreter::TreeInterpreter`].
+
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, string::String, vec::Vec};
+
+use crate::variable::Rcvar;
+
+/// A `key: value` pair inside a `MultiHash` (`{a: b, c: d}`) node.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyValuePair {
+    pub key: Ast,
+    pub value: Ast,
+}
+
+/// The comparison operators supported by `Ast::Comparison`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparator {
+    Eq,
+    Ne,
+    Lt,
+    Lte,
+    Gt,
+    Gte,
+}
+
+/// A parsed JMESPath expression.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Ast {
+    /// `lhs.rhs`
+    Subexpr { lhs: Box<Ast>, rhs: Box<Ast>, offset: usize },
+    /// A named field, e.g. `foo` in `foo.bar`.
+    Field { name: String, offset: usize },
+    /// `@`
+    Identity { offset: usize },
+    /// A literal value produced by a backtick-quoted raw JSON literal.
+    Literal { value: Rcvar, offset: usize },
+    /// `[n]`
+    Index { idx: i32, offset: usize },
+    /// `lhs || rhs`
+    Or { lhs: Box<Ast>, rhs: Box<Ast>, offset: usize },
+    /// `lhs && rhs`
+    And { lhs: Box<Ast>, rhs: Box<Ast>, offset: usize },
+    /// `!node`
+    Not { node: Box<Ast>, offset: usize },
+    /// `predicate && then` style filter condition, e.g. `[?a == b]`.
+    Condition { predicate: Box<Ast>, then: Box<Ast>, offset: usize },
+    /// `lhs <comparator> rhs`
+    Comparison { comparator: Comparator, lhs: Box<Ast>, rhs: Box<Ast>, offset: usize },
+    /// `node.*`
+    ObjectValues { node: Box<Ast>, offset: usize },
+    /// `lhs[*]rhs`/`lhs[?...]rhs` style projection.
+    Projection { lhs: Box<Ast>, rhs: Box<Ast>, offset: usize },
+    /// `node[]`
+    Flatten { node: Box<Ast>, offset: usize },
+    /// `[a, b, c]`
+    MultiList { elements: Vec<Ast>, offset: usize },
+    /// `{a: b, c: d}`
+    MultiHash { elements: Vec<KeyValuePair>, offset: usize },
+    /// `name(args, ...)`
+    Function { name: String, args: Vec<Ast>, offset: usize },
+    /// `&node`, an unevaluated expression reference.
+    Expref { ast: Box<Ast>, offset: usize },
+    /// `[start:stop:step]`
+    Slice { start: Option<i32>, stop: Option<i32>, step: i32, offset: usize },
+}
@@ -0,0 +1,591 @@
+//! The core JMESPath built-in functions, registered by
+//! [`crate::functions::register_core_functions`].
+
+#[cfg(feature = "std")]
+use std::cmp::Ordering;
+#[cfg(not(feature = "std"))]
+use core::cmp::Ordering;
+#[cfg(feature = "std")]
+use std::collections::BTreeMap;
+#[cfg(not(feature = "std"))]
+use alloc::collections::BTreeMap;
+#[cfg(feature = "std")]
+use std::sync::Arc;
+#[cfg(not(feature = "std"))]
+use alloc::sync::Arc;
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::{String, ToString}, vec, vec::Vec};
+
+use crate::errors::RuntimeError;
+use crate::functions::{validate_arity, ArgumentType, Function, Functions, Signature};
+use crate::interpreter::{Context, SearchResult};
+use crate::variable::{Rcvar, Variable};
+
+fn invalid_type(function: &str, position: usize, expected: &str, actual: &Variable, ctx: &mut Context) -> RuntimeError {
+    RuntimeError::InvalidArgumentType {
+        coordinates: ctx.create_coordinates(),
+        expression: ctx.expression.to_string(),
+        function: function.to_string(),
+        position,
+        expected: expected.to_string(),
+        actual: actual.get_type().to_string(),
+    }
+}
+
+fn expect_number(value: &Rcvar, name: &str, position: usize, ctx: &mut Context) -> Result<f64, RuntimeError> {
+    match **value {
+        Variable::Number(n) => Ok(n),
+        ref other => Err(invalid_type(name, position, "number", other, ctx)),
+    }
+}
+
+fn expect_string(value: &Rcvar, name: &str, position: usize, ctx: &mut Context) -> Result<String, RuntimeError> {
+    match **value {
+        Variable::String(ref s) => Ok(s.clone()),
+        ref other => Err(invalid_type(name, position, "string", other, ctx)),
+    }
+}
+
+fn expect_array<'a>(value: &'a Rcvar, name: &str, position: usize, ctx: &mut Context) -> Result<&'a Vec<Rcvar>, RuntimeError> {
+    match **value {
+        Variable::Array(ref a) => Ok(a),
+        ref other => Err(invalid_type(name, position, "array", other, ctx)),
+    }
+}
+
+fn expect_object(value: &Rcvar, name: &str, position: usize, ctx: &mut Context) -> Result<BTreeMap<String, Rcvar>, RuntimeError> {
+    match **value {
+        Variable::Object(ref o) => Ok(o.clone()),
+        ref other => Err(invalid_type(name, position, "object", other, ctx)),
+    }
+}
+
+fn numeric_cmp(a: f64, b: f64) -> Ordering {
+    a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+}
+
+/// Renders a `Variable` as a JSON string, used by `to_string` for non-string
+/// values.
+fn to_json_string(value: &Variable) -> String {
+    match *value {
+        Variable::Null => "null".to_string(),
+        Variable::Bool(b) => b.to_string(),
+        Variable::Number(n) => n.to_string(),
+        Variable::String(ref s) => format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\"")),
+        Variable::Array(ref a) => {
+            let items: Vec<String> = a.iter().map(|v| to_json_string(v)).collect();
+            format!("[{}]", items.join(","))
+        },
+        Variable::Object(ref o) => {
+            let items: Vec<String> = o.iter().map(|(k, v)| format!("\"{}\":{}", k, to_json_string(v))).collect();
+            format!("{{{}}}", items.join(","))
+        },
+        Variable::Expref(_) => "null".to_string(),
+    }
+}
+
+/// Evaluates an `Ast::Expref` value (e.g. the `&name` in `sort_by(@, &name)`)
+/// against a single element.
+fn eval_expref(expref: &Rcvar, element: &Rcvar, ctx: &mut Context) -> SearchResult {
+    match **expref {
+        Variable::Expref(ref ast) => ctx.interpreter.interpret(element, ast, ctx),
+        ref other => Err(invalid_type("expref", 0, "expref", other, ctx)),
+    }
+}
+
+macro_rules! simple_function {
+    ($struct_name:ident) => {
+        #[derive(Debug, Default)]
+        pub struct $struct_name;
+
+        impl $struct_name {
+            pub fn new() -> $struct_name {
+                $struct_name
+            }
+        }
+    };
+}
+
+macro_rules! unary_number_fn {
+    ($struct_name:ident, $fn_name:expr, $op:expr) => {
+        simple_function!($struct_name);
+
+        impl Function for $struct_name {
+            fn signature(&self) -> Signature {
+                Signature::new(vec![ArgumentType::Number])
+            }
+
+            fn evaluate(&self, args: &[Rcvar], ctx: &mut Context) -> SearchResult {
+                validate_arity($fn_name, args.len(), &self.signature(), ctx)?;
+                let n = expect_number(&args[0], $fn_name, 0, ctx)?;
+                Ok(ctx.interpreter.allocator.alloc(Variable::Number($op(n))))
+            }
+        }
+    };
+}
+
+#[cfg(feature = "std")]
+unary_number_fn!(AbsFn, "abs", f64::abs);
+#[cfg(feature = "std")]
+unary_number_fn!(CeilFn, "ceil", f64::ceil);
+#[cfg(feature = "std")]
+unary_number_fn!(FloorFn, "floor", f64::floor);
+
+// `f64::abs`/`ceil`/`floor` are `std`-only (they're implemented via the
+// platform's libm); fall back to the `libm` crate's software implementations
+// so these builtins stay available in `no_std` builds.
+#[cfg(not(feature = "std"))]
+unary_number_fn!(AbsFn, "abs", libm::fabs);
+#[cfg(not(feature = "std"))]
+unary_number_fn!(CeilFn, "ceil", libm::ceil);
+#[cfg(not(feature = "std"))]
+unary_number_fn!(FloorFn, "floor", libm::floor);
+
+macro_rules! string_prefix_fn {
+    ($struct_name:ident, $fn_name:expr, $method:ident) => {
+        simple_function!($struct_name);
+
+        impl Function for $struct_name {
+            fn signature(&self) -> Signature {
+                Signature::new(vec![ArgumentType::String, ArgumentType::String])
+            }
+
+            fn evaluate(&self, args: &[Rcvar], ctx: &mut Context) -> SearchResult {
+                validate_arity($fn_name, args.len(), &self.signature(), ctx)?;
+                let subject = expect_string(&args[0], $fn_name, 0, ctx)?;
+                let search = expect_string(&args[1], $fn_name, 1, ctx)?;
+                Ok(ctx.interpreter.allocator.alloc_bool(subject.$method(search.as_str())))
+            }
+        }
+    };
+}
+
+string_prefix_fn!(StartsWithFn, "starts_with", starts_with);
+string_prefix_fn!(EndsWithFn, "ends_with", ends_with);
+
+simple_function!(ContainsFn);
+impl Function for ContainsFn {
+    fn signature(&self) -> Signature {
+        Signature::new(vec![ArgumentType::Any, ArgumentType::Any])
+    }
+
+    fn evaluate(&self, args: &[Rcvar], ctx: &mut Context) -> SearchResult {
+        validate_arity("contains", args.len(), &self.signature(), ctx)?;
+        let result = match *args[0] {
+            Variable::String(ref s) => match *args[1] {
+                Variable::String(ref needle) => s.contains(needle.as_str()),
+                _ => false,
+            },
+            Variable::Array(ref a) => a.iter().any(|v| v == &args[1]),
+            ref other => return Err(invalid_type("contains", 0, "string or array", other, ctx)),
+        };
+        Ok(ctx.interpreter.allocator.alloc_bool(result))
+    }
+}
+
+simple_function!(JoinFn);
+impl Function for JoinFn {
+    fn signature(&self) -> Signature {
+        Signature::new(vec![ArgumentType::String, ArgumentType::Array])
+    }
+
+    fn evaluate(&self, args: &[Rcvar], ctx: &mut Context) -> SearchResult {
+        validate_arity("join", args.len(), &self.signature(), ctx)?;
+        let glue = expect_string(&args[0], "join", 0, ctx)?;
+        let array = expect_array(&args[1], "join", 1, ctx)?;
+        let mut parts = Vec::with_capacity(array.len());
+        for (i, item) in array.iter().enumerate() {
+            parts.push(expect_string(item, "join", i, ctx)?);
+        }
+        Ok(ctx.interpreter.allocator.alloc(Variable::String(parts.join(&glue))))
+    }
+}
+
+simple_function!(KeysFn);
+impl Function for KeysFn {
+    fn signature(&self) -> Signature {
+        Signature::new(vec![ArgumentType::Object])
+    }
+
+    fn evaluate(&self, args: &[Rcvar], ctx: &mut Context) -> SearchResult {
+        validate_arity("keys", args.len(), &self.signature(), ctx)?;
+        let object = expect_object(&args[0], "keys", 0, ctx)?;
+        let keys = object
+            .keys()
+            .map(|k| ctx.interpreter.allocator.alloc(Variable::String(k.clone())))
+            .collect::<Vec<Rcvar>>();
+        Ok(ctx.interpreter.allocator.alloc(Variable::Array(keys)))
+    }
+}
+
+simple_function!(ValuesFn);
+impl Function for ValuesFn {
+    fn signature(&self) -> Signature {
+        Signature::new(vec![ArgumentType::Object])
+    }
+
+    fn evaluate(&self, args: &[Rcvar], ctx: &mut Context) -> SearchResult {
+        validate_arity("values", args.len(), &self.signature(), ctx)?;
+        let object = expect_object(&args[0], "values", 0, ctx)?;
+        Ok(ctx.interpreter.allocator.alloc(Variable::Array(object.values().cloned().collect())))
+    }
+}
+
+simple_function!(LengthFn);
+impl Function for LengthFn {
+    fn signature(&self) -> Signature {
+        Signature::new(vec![ArgumentType::Any])
+    }
+
+    fn evaluate(&self, args: &[Rcvar], ctx: &mut Context) -> SearchResult {
+        validate_arity("length", args.len(), &self.signature(), ctx)?;
+        let len = match *args[0] {
+            Variable::String(ref s) => s.chars().count(),
+            Variable::Array(ref a) => a.len(),
+            Variable::Object(ref o) => o.len(),
+            ref other => return Err(invalid_type("length", 0, "string, array, or object", other, ctx)),
+        };
+        Ok(ctx.interpreter.allocator.alloc(Variable::Number(len as f64)))
+    }
+}
+
+simple_function!(ToArrayFn);
+impl Function for ToArrayFn {
+    fn signature(&self) -> Signature {
+        Signature::new(vec![ArgumentType::Any])
+    }
+
+    fn evaluate(&self, args: &[Rcvar], ctx: &mut Context) -> SearchResult {
+        validate_arity("to_array", args.len(), &self.signature(), ctx)?;
+        match *args[0] {
+            Variable::Array(_) => Ok(args[0].clone()),
+            _ => Ok(ctx.interpreter.allocator.alloc(Variable::Array(vec![args[0].clone()]))),
+        }
+    }
+}
+
+simple_function!(ToNumberFn);
+impl Function for ToNumberFn {
+    fn signature(&self) -> Signature {
+        Signature::new(vec![ArgumentType::Any])
+    }
+
+    fn evaluate(&self, args: &[Rcvar], ctx: &mut Context) -> SearchResult {
+        validate_arity("to_number", args.len(), &self.signature(), ctx)?;
+        match *args[0] {
+            Variable::Number(n) => Ok(ctx.interpreter.allocator.alloc(Variable::Number(n))),
+            Variable::String(ref s) => match s.parse::<f64>() {
+                Ok(n) => Ok(ctx.interpreter.allocator.alloc(Variable::Number(n))),
+                Err(_) => Ok(ctx.interpreter.allocator.alloc_null()),
+            },
+            _ => Ok(ctx.interpreter.allocator.alloc_null()),
+        }
+    }
+}
+
+simple_function!(ToStringFn);
+impl Function for ToStringFn {
+    fn signature(&self) -> Signature {
+        Signature::new(vec![ArgumentType::Any])
+    }
+
+    fn evaluate(&self, args: &[Rcvar], ctx: &mut Context) -> SearchResult {
+        validate_arity("to_string", args.len(), &self.signature(), ctx)?;
+        let s = match *args[0] {
+            Variable::String(ref s) => s.clone(),
+            ref other => to_json_string(other),
+        };
+        Ok(ctx.interpreter.allocator.alloc(Variable::String(s)))
+    }
+}
+
+simple_function!(TypeFn);
+impl Function for TypeFn {
+    fn signature(&self) -> Signature {
+        Signature::new(vec![ArgumentType::Any])
+    }
+
+    fn evaluate(&self, args: &[Rcvar], ctx: &mut Context) -> SearchResult {
+        validate_arity("type", args.len(), &self.signature(), ctx)?;
+        Ok(ctx.interpreter.allocator.alloc(Variable::String(args[0].get_type().to_string())))
+    }
+}
+
+simple_function!(NotNullFn);
+impl Function for NotNullFn {
+    fn signature(&self) -> Signature {
+        Signature::new(vec![ArgumentType::Any]).with_variadic(ArgumentType::Any)
+    }
+
+    fn evaluate(&self, args: &[Rcvar], ctx: &mut Context) -> SearchResult {
+        validate_arity("not_null", args.len(), &self.signature(), ctx)?;
+        for arg in args {
+            if !arg.is_null() {
+                return Ok(arg.clone());
+            }
+        }
+        Ok(ctx.interpreter.allocator.alloc_null())
+    }
+}
+
+simple_function!(MergeFn);
+impl Function for MergeFn {
+    fn signature(&self) -> Signature {
+        Signature::new(vec![]).with_variadic(ArgumentType::Object)
+    }
+
+    fn evaluate(&self, args: &[Rcvar], ctx: &mut Context) -> SearchResult {
+        validate_arity("merge", args.len(), &self.signature(), ctx)?;
+        let mut merged = BTreeMap::new();
+        for (i, arg) in args.iter().enumerate() {
+            let object = expect_object(arg, "merge", i, ctx)?;
+            merged.extend(object);
+        }
+        Ok(ctx.interpreter.allocator.alloc(Variable::Object(merged)))
+    }
+}
+
+simple_function!(ReverseFn);
+impl Function for ReverseFn {
+    fn signature(&self) -> Signature {
+        Signature::new(vec![ArgumentType::Any])
+    }
+
+    fn evaluate(&self, args: &[Rcvar], ctx: &mut Context) -> SearchResult {
+        validate_arity("reverse", args.len(), &self.signature(), ctx)?;
+        match *args[0] {
+            Variable::Array(ref a) => {
+                let mut reversed = a.clone();
+                reversed.reverse();
+                Ok(ctx.interpreter.allocator.alloc(Variable::Array(reversed)))
+            },
+            Variable::String(ref s) => {
+                let reversed: String = s.chars().rev().collect();
+                Ok(ctx.interpreter.allocator.alloc(Variable::String(reversed)))
+            },
+            ref other => Err(invalid_type("reverse", 0, "array or string", other, ctx)),
+        }
+    }
+}
+
+simple_function!(SumFn);
+impl Function for SumFn {
+    fn signature(&self) -> Signature {
+        Signature::new(vec![ArgumentType::Array])
+    }
+
+    fn evaluate(&self, args: &[Rcvar], ctx: &mut Context) -> SearchResult {
+        validate_arity("sum", args.len(), &self.signature(), ctx)?;
+        let array = expect_array(&args[0], "sum", 0, ctx)?;
+        let mut total = 0.0;
+        for (i, item) in array.iter().enumerate() {
+            total += expect_number(item, "sum", i, ctx)?;
+        }
+        Ok(ctx.interpreter.allocator.alloc(Variable::Number(total)))
+    }
+}
+
+simple_function!(AvgFn);
+impl Function for AvgFn {
+    fn signature(&self) -> Signature {
+        Signature::new(vec![ArgumentType::Array])
+    }
+
+    fn evaluate(&self, args: &[Rcvar], ctx: &mut Context) -> SearchResult {
+        validate_arity("avg", args.len(), &self.signature(), ctx)?;
+        let array = expect_array(&args[0], "avg", 0, ctx)?;
+        if array.is_empty() {
+            return Ok(ctx.interpreter.allocator.alloc_null());
+        }
+        let mut total = 0.0;
+        for (i, item) in array.iter().enumerate() {
+            total += expect_number(item, "avg", i, ctx)?;
+        }
+        Ok(ctx.interpreter.allocator.alloc(Variable::Number(total / array.len() as f64)))
+    }
+}
+
+fn min_or_max(array: &[Rcvar], name: &str, ctx: &mut Context, keep: Ordering) -> SearchResult {
+    if array.is_empty() {
+        return Ok(ctx.interpreter.allocator.alloc_null());
+    }
+    let mut best = array[0].clone();
+    for item in &array[1..] {
+        let ordering = match (&*best, &**item) {
+            (&Variable::Number(a), &Variable::Number(b)) => numeric_cmp(a, b),
+            (Variable::String(a), Variable::String(b)) => a.cmp(b),
+            _ => return Err(invalid_type(name, 0, "array[number] or array[string]", item, ctx)),
+        };
+        if ordering == keep {
+            best = item.clone();
+        }
+    }
+    Ok(best)
+}
+
+simple_function!(MinFn);
+impl Function for MinFn {
+    fn signature(&self) -> Signature {
+        Signature::new(vec![ArgumentType::Array])
+    }
+
+    fn evaluate(&self, args: &[Rcvar], ctx: &mut Context) -> SearchResult {
+        validate_arity("min", args.len(), &self.signature(), ctx)?;
+        let array = expect_array(&args[0], "min", 0, ctx)?;
+        min_or_max(array, "min", ctx, Ordering::Less)
+    }
+}
+
+simple_function!(MaxFn);
+impl Function for MaxFn {
+    fn signature(&self) -> Signature {
+        Signature::new(vec![ArgumentType::Array])
+    }
+
+    fn evaluate(&self, args: &[Rcvar], ctx: &mut Context) -> SearchResult {
+        validate_arity("max", args.len(), &self.signature(), ctx)?;
+        let array = expect_array(&args[0], "max", 0, ctx)?;
+        min_or_max(array, "max", ctx, Ordering::Greater)
+    }
+}
+
+fn by_extreme(array: &[Rcvar], expref: &Rcvar, name: &str, ctx: &mut Context, keep: Ordering) -> SearchResult {
+    if array.is_empty() {
+        return Ok(ctx.interpreter.allocator.alloc_null());
+    }
+    let mut best_element = array[0].clone();
+    let mut best_key = eval_expref(expref, &best_element, ctx)?;
+    for element in &array[1..] {
+        let key = eval_expref(expref, element, ctx)?;
+        let ordering = match (&*key, &*best_key) {
+            (&Variable::Number(a), &Variable::Number(b)) => numeric_cmp(a, b),
+            (Variable::String(a), Variable::String(b)) => a.cmp(b),
+            ref other => return Err(invalid_type(name, 1, "expref returning number or string", other.0, ctx)),
+        };
+        if ordering == keep {
+            best_key = key;
+            best_element = element.clone();
+        }
+    }
+    Ok(best_element)
+}
+
+simple_function!(MaxByFn);
+impl Function for MaxByFn {
+    fn signature(&self) -> Signature {
+        Signature::new(vec![ArgumentType::Array, ArgumentType::Expref])
+    }
+
+    fn evaluate(&self, args: &[Rcvar], ctx: &mut Context) -> SearchResult {
+        validate_arity("max_by", args.len(), &self.signature(), ctx)?;
+        let array = expect_array(&args[0], "max_by", 0, ctx)?;
+        by_extreme(array, &args[1], "max_by", ctx, Ordering::Greater)
+    }
+}
+
+simple_function!(MinByFn);
+impl Function for MinByFn {
+    fn signature(&self) -> Signature {
+        Signature::new(vec![ArgumentType::Array, ArgumentType::Expref])
+    }
+
+    fn evaluate(&self, args: &[Rcvar], ctx: &mut Context) -> SearchResult {
+        validate_arity("min_by", args.len(), &self.signature(), ctx)?;
+        let array = expect_array(&args[0], "min_by", 0, ctx)?;
+        by_extreme(array, &args[1], "min_by", ctx, Ordering::Less)
+    }
+}
+
+simple_function!(SortFn);
+impl Function for SortFn {
+    fn signature(&self) -> Signature {
+        Signature::new(vec![ArgumentType::Array])
+    }
+
+    fn evaluate(&self, args: &[Rcvar], ctx: &mut Context) -> SearchResult {
+        validate_arity("sort", args.len(), &self.signature(), ctx)?;
+        let mut array = expect_array(&args[0], "sort", 0, ctx)?.clone();
+        for item in &array {
+            match **item {
+                Variable::Number(_) | Variable::String(_) => {},
+                ref other => return Err(invalid_type("sort", 0, "array[number] or array[string]", other, ctx)),
+            }
+        }
+        array.sort_by(|a, b| match (&**a, &**b) {
+            (&Variable::Number(x), &Variable::Number(y)) => numeric_cmp(x, y),
+            (Variable::String(x), Variable::String(y)) => x.cmp(y),
+            _ => Ordering::Equal,
+        });
+        Ok(ctx.interpreter.allocator.alloc(Variable::Array(array)))
+    }
+}
+
+simple_function!(SortByFn);
+impl Function for SortByFn {
+    fn signature(&self) -> Signature {
+        Signature::new(vec![ArgumentType::Array, ArgumentType::Expref])
+    }
+
+    fn evaluate(&self, args: &[Rcvar], ctx: &mut Context) -> SearchResult {
+        validate_arity("sort_by", args.len(), &self.signature(), ctx)?;
+        let array = expect_array(&args[0], "sort_by", 0, ctx)?;
+        let mut keyed = Vec::with_capacity(array.len());
+        for element in array {
+            let key = eval_expref(&args[1], element, ctx)?;
+            keyed.push((key, element.clone()));
+        }
+        keyed.sort_by(|a, b| match (&*a.0, &*b.0) {
+            (&Variable::Number(x), &Variable::Number(y)) => numeric_cmp(x, y),
+            (Variable::String(x), Variable::String(y)) => x.cmp(y),
+            _ => Ordering::Equal,
+        });
+        Ok(ctx.interpreter.allocator.alloc(Variable::Array(keyed.into_iter().map(|(_, v)| v).collect())))
+    }
+}
+
+simple_function!(MapFn);
+impl Function for MapFn {
+    fn signature(&self) -> Signature {
+        Signature::new(vec![ArgumentType::Expref, ArgumentType::Array])
+    }
+
+    fn evaluate(&self, args: &[Rcvar], ctx: &mut Context) -> SearchResult {
+        validate_arity("map", args.len(), &self.signature(), ctx)?;
+        let array = expect_array(&args[1], "map", 1, ctx)?;
+        let mut mapped = Vec::with_capacity(array.len());
+        for element in array {
+            mapped.push(eval_expref(&args[0], element, ctx)?);
+        }
+        Ok(ctx.interpreter.allocator.alloc(Variable::Array(mapped)))
+    }
+}
+
+/// Registers the core (always-available) JMESPath functions.
+pub fn register(functions: &mut Functions) {
+    functions.insert("abs".to_string(), Arc::new(AbsFn::new()));
+    functions.insert("avg".to_string(), Arc::new(AvgFn::new()));
+    functions.insert("ceil".to_string(), Arc::new(CeilFn::new()));
+    functions.insert("contains".to_string(), Arc::new(ContainsFn::new()));
+    functions.insert("ends_with".to_string(), Arc::new(EndsWithFn::new()));
+    functions.insert("floor".to_string(), Arc::new(FloorFn::new()));
+    functions.insert("join".to_string(), Arc::new(JoinFn::new()));
+    functions.insert("keys".to_string(), Arc::new(KeysFn::new()));
+    functions.insert("length".to_string(), Arc::new(LengthFn::new()));
+    functions.insert("map".to_string(), Arc::new(MapFn::new()));
+    functions.insert("min".to_string(), Arc::new(MinFn::new()));
+    functions.insert("max".to_string(), Arc::new(MaxFn::new()));
+    functions.insert("max_by".to_string(), Arc::new(MaxByFn::new()));
+    functions.insert("min_by".to_string(), Arc::new(MinByFn::new()));
+    functions.insert("merge".to_string(), Arc::new(MergeFn::new()));
+    functions.insert("not_null".to_string(), Arc::new(NotNullFn::new()));
+    functions.insert("reverse".to_string(), Arc::new(ReverseFn::new()));
+    functions.insert("sort".to_string(), Arc::new(SortFn::new()));
+    functions.insert("sort_by".to_string(), Arc::new(SortByFn::new()));
+    functions.insert("starts_with".to_string(), Arc::new(StartsWithFn::new()));
+    functions.insert("sum".to_string(), Arc::new(SumFn::new()));
+    functions.insert("to_array".to_string(), Arc::new(ToArrayFn::new()));
+    functions.insert("to_number".to_string(), Arc::new(ToNumberFn::new()));
+    functions.insert("to_string".to_string(), Arc::new(ToStringFn::new()));
+    functions.insert("type".to_string(), Arc::new(TypeFn::new()));
+    functions.insert("values".to_string(), Arc::new(ValuesFn::new()));
+}
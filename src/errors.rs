@@ -0,0 +1,173 @@
+//! Error types produced while parsing or interpreting JMESPath expressions.
+
+#[cfg(feature = "std")]
+use std::fmt;
+#[cfg(not(feature = "std"))]
+use core::fmt;
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+/// Identifies a location in an expression string for error reporting.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Coordinates {
+    /// Zero-based line number.
+    pub line: usize,
+    /// Zero-based column number within `line`.
+    pub column: usize,
+    /// Byte offset into the original expression string.
+    pub offset: usize,
+}
+
+impl Coordinates {
+    /// Computes line/column coordinates for a byte offset into `expression`.
+    pub fn from_offset(expression: &str, offset: usize) -> Coordinates {
+        let mut line = 0;
+        let mut column = 0;
+        for ch in expression.chars().take(offset) {
+            if ch == '\n' {
+                line += 1;
+                column = 0;
+            } else {
+                column += 1;
+            }
+        }
+        Coordinates { line, column, offset }
+    }
+}
+
+/// Errors that occur while interpreting an AST against input data, or while
+/// statically validating an AST ahead of time.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RuntimeError {
+    /// An `Ast::Function` node referenced a function name that isn't
+    /// registered with the interpreter.
+    UnknownFunction {
+        coordinates: Coordinates,
+        expression: String,
+        function: String,
+    },
+    /// A `MultiHash` key evaluated to something other than a string.
+    InvalidKey {
+        coordinates: Coordinates,
+        expression: String,
+        actual: String,
+    },
+    /// A `Slice` node's step was `0`.
+    InvalidSlice {
+        coordinates: Coordinates,
+        expression: String,
+    },
+    /// A function was called with too few or too many arguments.
+    InvalidArity {
+        coordinates: Coordinates,
+        expression: String,
+        function: String,
+        expected_min: usize,
+        expected_max: usize,
+        actual: usize,
+    },
+    /// A function argument's statically-known type didn't match the
+    /// function's declared signature.
+    InvalidArgumentType {
+        coordinates: Coordinates,
+        expression: String,
+        function: String,
+        position: usize,
+        expected: String,
+        actual: String,
+    },
+    /// An argument to a function was otherwise malformed, e.g. an invalid
+    /// regular expression pattern.
+    InvalidArgument {
+        coordinates: Coordinates,
+        expression: String,
+        message: String,
+    },
+    /// A function argument had the wrong runtime `Variable` type.
+    InvalidType {
+        coordinates: Coordinates,
+        expression: String,
+        expected: String,
+        actual: String,
+    },
+}
+
+impl fmt::Display for RuntimeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RuntimeError::UnknownFunction { ref function, .. } => {
+                write!(f, "Call to undefined function {}", function)
+            },
+            RuntimeError::InvalidKey { ref actual, .. } => {
+                write!(f, "Invalid key type, expected string, found {}", actual)
+            },
+            RuntimeError::InvalidSlice { .. } => write!(f, "Invalid slice, step cannot be 0"),
+            RuntimeError::InvalidArity { ref function, expected_min, expected_max, actual, .. } => {
+                if expected_max == usize::MAX {
+                    write!(f, "{}() takes at least {} argument(s) but {} were provided", function, expected_min, actual)
+                } else {
+                    write!(f, "{}() takes between {} and {} argument(s) but {} were provided", function, expected_min, expected_max, actual)
+                }
+            },
+            RuntimeError::InvalidArgumentType { ref function, position, ref expected, ref actual, .. } => {
+                write!(f, "Argument {} of {}() expects type {}, found {}", position, function, expected, actual)
+            },
+            RuntimeError::InvalidArgument { ref message, .. } => write!(f, "Invalid argument: {}", message),
+            RuntimeError::InvalidType { ref expected, ref actual, .. } => {
+                write!(f, "Invalid type, expected {}, found {}", expected, actual)
+            },
+        }
+    }
+}
+
+// `std::error::Error` is only meaningful (and only available) with `std`;
+// `no_std` builds still get the full `RuntimeError` type above, just without
+// this trait impl.
+#[cfg(feature = "std")]
+impl std::error::Error for RuntimeError {}
+
+/// A failure to parse an expression string into an `Ast`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub expression: String,
+    pub offset: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Parse error at offset {}: {}", self.offset, self.message)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+/// The top-level error type returned by `Runtime::compile` and
+/// `Expression::search`/`validate`: either the expression failed to parse,
+/// or it failed during (or before) evaluation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JmespathError {
+    Parse { expression: String, offset: usize, message: String },
+    Runtime(RuntimeError),
+}
+
+impl From<RuntimeError> for JmespathError {
+    fn from(err: RuntimeError) -> JmespathError {
+        JmespathError::Runtime(err)
+    }
+}
+
+impl fmt::Display for JmespathError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            JmespathError::Parse { offset, ref message, .. } => {
+                write!(f, "Parse error at offset {}: {}", offset, message)
+            },
+            JmespathError::Runtime(ref err) => err.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for JmespathError {}
@@ -0,0 +1,58 @@
+//! A compiled JMESPath expression, produced by [`Runtime::compile`].
+
+#[cfg(feature = "std")]
+use std::sync::Arc;
+#[cfg(not(feature = "std"))]
+use alloc::sync::Arc;
+#[cfg(not(feature = "std"))]
+use alloc::string::{String, ToString};
+
+use super::ast::Ast;
+use super::errors::JmespathError;
+use super::interpreter::Context;
+use super::runtime::Runtime;
+use super::variable::Rcvar;
+
+/// A parsed JMESPath expression, ready to be evaluated against input data.
+///
+/// Cheap to clone: the parsed `Ast` is reference-counted and the runtime it
+/// was compiled against is only borrowed.
+#[derive(Clone)]
+pub struct Expression<'a> {
+    expression: String,
+    ast: Arc<Ast>,
+    runtime: &'a Runtime,
+}
+
+impl<'a> Expression<'a> {
+    pub(crate) fn new(expression: &str, ast: Arc<Ast>, runtime: &'a Runtime) -> Expression<'a> {
+        Expression { expression: expression.to_string(), ast, runtime }
+    }
+
+    /// Returns the original expression string this was compiled from.
+    pub fn as_str(&self) -> &str {
+        &self.expression
+    }
+
+    /// Statically validates this expression's function calls — arity and
+    /// statically-known argument types — without evaluating it against data.
+    pub fn validate(&self) -> Result<(), JmespathError> {
+        self.runtime
+            .interpreter
+            .validate(&self.ast, &self.expression)
+            .map_err(JmespathError::from)
+    }
+
+    /// Evaluates this expression against `data`.
+    pub fn search(&self, data: Rcvar) -> Result<Rcvar, JmespathError> {
+        let mut ctx = Context {
+            interpreter: &self.runtime.interpreter,
+            expression: &self.expression,
+            offset: 0,
+        };
+        self.runtime
+            .interpreter
+            .interpret(&data, &self.ast, &mut ctx)
+            .map_err(JmespathError::from)
+    }
+}
@@ -0,0 +1,192 @@
+//! Registers and describes the functions callable from JMESPath expressions.
+
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(not(feature = "std"))]
+use hashbrown::HashMap;
+#[cfg(feature = "std")]
+use std::sync::Arc;
+#[cfg(not(feature = "std"))]
+use alloc::sync::Arc;
+#[cfg(not(feature = "std"))]
+use alloc::string::{String, ToString};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+
+use super::ast::Ast;
+use super::errors::RuntimeError;
+use super::interpreter::{Context, SearchResult};
+use super::variable::Rcvar;
+
+/// Maps a JMESPath function name to its implementation.
+pub type Functions = HashMap<String, Arc<dyn Function>>;
+
+/// A function that can be called from a JMESPath expression.
+pub trait Function {
+    /// Evaluates the function against its already-evaluated arguments.
+    fn evaluate(&self, args: &[Rcvar], ctx: &mut Context) -> SearchResult;
+
+    /// Describes the function's expected arguments so that `TreeInterpreter::validate`
+    /// can check arity and statically-known argument types before evaluation.
+    fn signature(&self) -> Signature;
+}
+
+/// Registers the core (always-available) JMESPath functions.
+pub fn register_core_functions(functions: &mut Functions) {
+    super::builtins::register(functions);
+}
+
+/// Checks `args_len` against `signature`, turning a mismatch into the same
+/// `RuntimeError::InvalidArity` that `TreeInterpreter::validate` would have
+/// caught ahead of time, so every `Function::evaluate` can guard itself
+/// against out-of-bounds `args[n]` panics with one call.
+pub fn validate_arity(name: &str, args_len: usize, signature: &Signature, ctx: &mut Context) -> Result<(), RuntimeError> {
+    signature.validate_arity(args_len).map_err(|(min, max)| RuntimeError::InvalidArity {
+        coordinates: ctx.create_coordinates(),
+        expression: ctx.expression.to_string(),
+        function: name.to_string(),
+        expected_min: min,
+        expected_max: max,
+        actual: args_len,
+    })
+}
+
+/// What, if anything, is statically known about an argument's type before
+/// it has been evaluated against input data.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StaticArgumentType {
+    /// The argument is a literal with a statically-known `Variable` type,
+    /// named the same as `Variable::get_type` would report (e.g. `"string"`).
+    Literal(String),
+    /// The argument is an `Ast::Expref`, as required by functions like
+    /// `sort_by`/`max_by` that take an expression reference.
+    Expref,
+}
+
+impl core::fmt::Display for StaticArgumentType {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match *self {
+            StaticArgumentType::Literal(ref t) => write!(f, "{}", t),
+            StaticArgumentType::Expref => write!(f, "expref"),
+        }
+    }
+}
+
+/// Returns `None` when an argument's runtime type can't be known until it's
+/// evaluated against input data (e.g. a field reference or projection).
+pub fn static_argument_type(node: &Ast) -> Option<StaticArgumentType> {
+    match node {
+        Ast::Literal { value, .. } => Some(StaticArgumentType::Literal(value.get_type().to_string())),
+        &Ast::Expref { .. } => Some(StaticArgumentType::Expref),
+        _ => None,
+    }
+}
+
+/// A declared argument type for a function `Signature`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ArgumentType {
+    /// Accepts any argument type, including expression references.
+    Any,
+    Null,
+    String,
+    Number,
+    Boolean,
+    Array,
+    Object,
+    /// An `&Ast::Expref`, e.g. the comparator expression passed to `sort_by`.
+    Expref,
+}
+
+impl ArgumentType {
+    fn type_name(&self) -> &'static str {
+        match *self {
+            ArgumentType::Any => "any",
+            ArgumentType::Null => "null",
+            ArgumentType::String => "string",
+            ArgumentType::Number => "number",
+            ArgumentType::Boolean => "boolean",
+            ArgumentType::Array => "array",
+            ArgumentType::Object => "object",
+            ArgumentType::Expref => "expref",
+        }
+    }
+
+    /// Returns whether a statically-known argument type satisfies this
+    /// declared argument type.
+    pub fn accepts(&self, actual: &StaticArgumentType) -> bool {
+        match (*self, actual) {
+            (ArgumentType::Any, _) => true,
+            (ArgumentType::Expref, &StaticArgumentType::Expref) => true,
+            (_, &StaticArgumentType::Expref) => false,
+            (expected, StaticArgumentType::Literal(actual_type)) => {
+                expected.type_name() == actual_type.as_str()
+            }
+        }
+    }
+}
+
+impl core::fmt::Display for ArgumentType {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}", self.type_name())
+    }
+}
+
+/// Declares the arguments a `Function` accepts: a required prefix, an
+/// optional suffix, and, for variadic functions, the type repeated for any
+/// further trailing arguments.
+#[derive(Debug, Clone, Default)]
+pub struct Signature {
+    pub required: Vec<ArgumentType>,
+    pub optional: Vec<ArgumentType>,
+    pub variadic: Option<ArgumentType>,
+}
+
+impl Signature {
+    pub fn new(required: Vec<ArgumentType>) -> Signature {
+        Signature { required, optional: vec![], variadic: None }
+    }
+
+    pub fn with_optional(mut self, optional: Vec<ArgumentType>) -> Signature {
+        self.optional = optional;
+        self
+    }
+
+    pub fn with_variadic(mut self, argument_type: ArgumentType) -> Signature {
+        self.variadic = Some(argument_type);
+        self
+    }
+
+    /// Validates that `actual` argument count is allowed by this signature.
+    ///
+    /// Returns `Err((min, max))` describing the allowed range on failure;
+    /// `max` is `usize::MAX` for variadic signatures.
+    pub fn validate_arity(&self, actual: usize) -> Result<(), (usize, usize)> {
+        let min = self.required.len();
+        let max = if self.variadic.is_some() {
+            usize::MAX
+        } else {
+            self.required.len() + self.optional.len()
+        };
+        if actual < min || actual > max {
+            Err((min, max))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Returns the declared type for each of the first `count` positional
+    /// arguments, repeating the variadic type past the required/optional
+    /// prefix.
+    pub fn argument_types(&self, count: usize) -> Vec<ArgumentType> {
+        let mut types: Vec<ArgumentType> = self.required.iter().cloned().chain(self.optional.iter().cloned()).collect();
+        if let Some(variadic) = self.variadic {
+            while types.len() < count {
+                types.push(variadic);
+            }
+        }
+        types.truncate(count);
+        types
+    }
+}
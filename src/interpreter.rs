@@ -1,13 +1,27 @@
 //! Interprets JMESPath expressions
 
+#[cfg(feature = "std")]
 use std::collections::{BTreeMap, HashMap};
+#[cfg(not(feature = "std"))]
+use alloc::collections::BTreeMap;
+#[cfg(not(feature = "std"))]
+use hashbrown::HashMap;
+// `Vec`/`String`/`vec!` are part of the standard prelude under `std`, but
+// need to come from `alloc` explicitly in `no_std` builds.
+#[cfg(not(feature = "std"))]
+use alloc::{borrow::ToOwned, string::ToString, vec, vec::Vec};
 
-use super::{Coordinates, RcVar, RuntimeError};
+#[cfg(feature = "std")]
+use std::sync::Arc;
+#[cfg(not(feature = "std"))]
+use alloc::sync::Arc;
+
+use super::{Coordinates, Rcvar, RuntimeError};
 use super::ast::Ast;
-use super::functions::{register_core_functions, JPFunction, Functions};
+use super::functions::{register_core_functions, static_argument_type, Function, Functions};
 use super::variable::{Variable, VariableAllocator};
 
-pub type SearchResult = Result<RcVar, RuntimeError>;
+pub type SearchResult = Result<Rcvar, RuntimeError>;
 
 /// TreeInterpreter context object used primarily for error reporting.
 pub struct Context<'a> {
@@ -33,6 +47,12 @@ pub struct TreeInterpreter {
     functions: Functions
 }
 
+impl Default for TreeInterpreter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl TreeInterpreter {
     /// Creates a new TreeInterpreter
     pub fn new() -> TreeInterpreter {
@@ -46,32 +66,75 @@ impl TreeInterpreter {
     pub fn with_functions(functions: Functions) -> TreeInterpreter {
         TreeInterpreter {
             allocator: VariableAllocator::new(),
-            functions: functions
+            functions
         }
     }
 
+    /// Adds a function to (or replaces one in) this interpreter's registry.
+    pub fn register_function(&mut self, name: &str, f: Arc<dyn Function>) {
+        self.functions.insert(name.to_owned(), f);
+    }
+
+    /// Removes a function from this interpreter's registry, returning it if
+    /// it was present.
+    pub fn deregister_function(&mut self, name: &str) -> Option<Arc<dyn Function>> {
+        self.functions.remove(name)
+    }
+
+    /// Looks up a registered function by name.
+    pub fn get_function(&self, name: &str) -> Option<&Arc<dyn Function>> {
+        self.functions.get(name)
+    }
+
+    /// (Re-)registers the core builtin functions with this interpreter.
+    ///
+    /// `TreeInterpreter::new` already does this; this is for interpreters
+    /// built via `with_functions` that want the core builtins layered in
+    /// alongside their own.
+    pub fn register_builtins(&mut self) {
+        register_core_functions(&mut self.functions);
+    }
+
     /// Interprets the given data using an AST node.
     #[inline(never)]
-    pub fn interpret(&self, data: &RcVar, node: &Ast, ctx: &mut Context) -> SearchResult {
+    pub fn interpret(&self, data: &Rcvar, node: &Ast, ctx: &mut Context) -> SearchResult {
         match node {
-            &Ast::Subexpr { ref lhs, ref rhs, ref offset } => {
-                ctx.offset = *offset;
-                let left_result = try!(self.interpret(data, lhs, ctx));
-                self.interpret(&left_result, rhs, ctx)
+            &Ast::Subexpr { .. } => {
+                // Subexprs are parsed right-leaning (`a.b.c` nests as
+                // `Subexpr(a, Subexpr(b, c))`), so a plain recursive
+                // implementation spends one stack frame and one Rcvar clone
+                // per segment of the chain. Walk it iteratively instead: only
+                // `lhs` (which doesn't itself chain to the right) recurses.
+                let mut current = data.clone();
+                let mut node = node;
+                loop {
+                    match node {
+                        Ast::Subexpr { lhs, rhs, offset } => {
+                            ctx.offset = *offset;
+                            current = self.interpret(&current, lhs, ctx)?;
+                            node = rhs;
+                        },
+                        Ast::Field { name, offset } => {
+                            ctx.offset = *offset;
+                            return Ok(current.get_value(name).unwrap_or_else(|| self.allocator.alloc_null()));
+                        },
+                        other => return self.interpret(&current, other, ctx),
+                    }
+                }
             },
-            &Ast::Field { ref name, ref offset } => {
+            Ast::Field { name, offset } => {
                 ctx.offset = *offset;
                 Ok(data.get_value(name).unwrap_or(self.allocator.alloc_null()))
             },
-            &Ast::Identity { ref offset } => {
+            Ast::Identity { offset } => {
                 ctx.offset = *offset;
                 Ok(data.clone())
             },
-            &Ast::Literal { ref value, ref offset } => {
+            Ast::Literal { value, offset } => {
                 ctx.offset = *offset;
                 Ok(value.clone())
             },
-            &Ast::Index { ref idx, ref offset } => {
+            Ast::Index { idx, offset } => {
                 ctx.offset = *offset;
                 match if *idx >= 0 {
                     data.get_index(*idx as usize)
@@ -82,68 +145,68 @@ impl TreeInterpreter {
                     None => Ok(self.allocator.alloc_null())
                 }
             },
-            &Ast::Or { ref lhs, ref rhs, ref offset } => {
+            Ast::Or { lhs, rhs, offset } => {
                 ctx.offset = *offset;
-                let left = try!(self.interpret(data, lhs, ctx));
+                let left = self.interpret(data, lhs, ctx)?;
                 if left.is_truthy() {
                     Ok(left)
                 } else {
                     self.interpret(data, rhs, ctx)
                 }
             },
-            &Ast::And { ref lhs, ref rhs, ref offset } => {
+            Ast::And { lhs, rhs, offset } => {
                 ctx.offset = *offset;
-                let left = try!(self.interpret(data, lhs, ctx));
+                let left = self.interpret(data, lhs, ctx)?;
                 if !left.is_truthy() {
                     Ok(left)
                 } else {
                     self.interpret(data, rhs, ctx)
                 }
             },
-            &Ast::Not { ref node, ref offset } => {
+            Ast::Not { node, offset } => {
                 ctx.offset = *offset;
-                let result = try!(self.interpret(data, node, ctx));
+                let result = self.interpret(data, node, ctx)?;
                 Ok(self.allocator.alloc_bool(!result.is_truthy()))
             },
             // Returns the resut of RHS if cond yields truthy value.
-            &Ast::Condition { ref predicate, ref then, ref offset } => {
+            Ast::Condition { predicate, then, offset } => {
                 ctx.offset = *offset;
-                let cond_result = try!(self.interpret(data, predicate, ctx));
+                let cond_result = self.interpret(data, predicate, ctx)?;
                 if cond_result.is_truthy() {
                     self.interpret(data, then, ctx)
                 } else {
                     Ok(self.allocator.alloc_null())
                 }
             },
-            &Ast::Comparison { ref comparator, ref lhs, ref rhs, ref offset } => {
+            Ast::Comparison { comparator, lhs, rhs, offset } => {
                 ctx.offset = *offset;
-                let left = try!(self.interpret(data, lhs, ctx));
-                let right = try!(self.interpret(data, rhs, ctx));
-                Ok(left.compare(comparator, &*right).map_or(
+                let left = self.interpret(data, lhs, ctx)?;
+                let right = self.interpret(data, rhs, ctx)?;
+                Ok(left.compare(comparator, &right).map_or(
                     self.allocator.alloc_null(),
                     |result| self.allocator.alloc_bool(result)))
             },
             // Converts an object into a JSON array of its values.
-            &Ast::ObjectValues { ref node, ref offset } => {
+            Ast::ObjectValues { node, offset } => {
                 ctx.offset = *offset;
-                let subject = try!(self.interpret(data, node, ctx));
+                let subject = self.interpret(data, node, ctx)?;
                 match *subject {
                     Variable::Object(ref v) => {
-                        Ok(self.allocator.alloc(v.values().cloned().collect::<Vec<RcVar>>()))
+                        Ok(self.allocator.alloc(v.values().cloned().collect::<Vec<Rcvar>>()))
                     },
                     _ => Ok(self.allocator.alloc_null())
                 }
             },
             // Passes the results of lhs into rhs if lhs yields an array and
             // each node of lhs that passes through rhs yields a non-null value.
-            &Ast::Projection { ref lhs, ref rhs, ref offset } => {
+            Ast::Projection { lhs, rhs, offset } => {
                 ctx.offset = *offset;
-                match try!(self.interpret(data, lhs, ctx)).as_array() {
+                match self.interpret(data, lhs, ctx)?.as_array() {
                     None => Ok(self.allocator.alloc_null()),
                     Some(left) => {
-                        let mut collected = vec![];
+                        let mut collected = Vec::with_capacity(left.len());
                         for element in left {
-                            let current = try!(self.interpret(element, rhs, ctx));
+                            let current = self.interpret(element, rhs, ctx)?;
                             if !current.is_null() {
                                 collected.push(current);
                             }
@@ -152,12 +215,12 @@ impl TreeInterpreter {
                     }
                 }
             },
-            &Ast::Flatten { ref node, ref offset } => {
+            Ast::Flatten { node, offset } => {
                 ctx.offset = *offset;
-                match try!(self.interpret(data, node, ctx)).as_array() {
+                match self.interpret(data, node, ctx)?.as_array() {
                     None => Ok(self.allocator.alloc_null()),
                     Some(a) => {
-                        let mut collected: Vec<RcVar> = vec![];
+                        let mut collected: Vec<Rcvar> = Vec::with_capacity(a.len());
                         for element in a {
                             match element.as_array() {
                                 Some(array) => collected.extend(array.iter().cloned()),
@@ -168,27 +231,27 @@ impl TreeInterpreter {
                     }
                 }
             },
-            &Ast::MultiList { ref elements, ref offset } => {
+            Ast::MultiList { elements, offset } => {
                 ctx.offset = *offset;
                 if data.is_null() {
                     Ok(self.allocator.alloc_null())
                 } else {
                     let mut collected = vec![];
                     for node in elements {
-                        collected.push(try!(self.interpret(data, node, ctx)));
+                        collected.push(self.interpret(data, node, ctx)?);
                     }
                     Ok(self.allocator.alloc(collected))
                 }
             },
-            &Ast::MultiHash { ref elements, ref offset } => {
+            Ast::MultiHash { elements, offset } => {
                 ctx.offset = *offset;
                 if data.is_null() {
                     Ok(self.allocator.alloc_null())
                 } else {
                     let mut collected = BTreeMap::new();
                     for kvp in elements {
-                        let key = try!(self.interpret(data, &kvp.key, ctx));
-                        let value = try!(self.interpret(data, &kvp.value, ctx));
+                        let key = self.interpret(data, &kvp.key, ctx)?;
+                        let value = self.interpret(data, &kvp.value, ctx)?;
                         if let Variable::String(ref s) = *key {
                             collected.insert(s.to_string(), value);
                         } else {
@@ -202,16 +265,16 @@ impl TreeInterpreter {
                     Ok(self.allocator.alloc(collected))
                 }
             },
-            &Ast::Function { ref name, ref args, ref offset } => {
+            Ast::Function { name, args, offset } => {
                 ctx.offset = *offset;
-                let mut fn_args: Vec<RcVar> = vec![];
+                let mut fn_args: Vec<Rcvar> = vec![];
                 for arg in args {
-                    fn_args.push(try!(self.interpret(data, arg, ctx)));
+                    fn_args.push(self.interpret(data, arg, ctx)?);
                 }
                 // Reset the offset so that it points to the function being evaluated.
                 ctx.offset = *offset;
                 match self.functions.get(name) {
-                    Some(f) => f.evaluate(fn_args, ctx),
+                    Some(f) => f.evaluate(&fn_args, ctx),
                     None => {
                         Err(RuntimeError::UnknownFunction {
                             coordinates: ctx.create_coordinates(),
@@ -221,7 +284,7 @@ impl TreeInterpreter {
                     }
                 }
             },
-            &Ast::Expref{ ref ast, ref offset } => {
+            Ast::Expref{ ast, offset } => {
                 ctx.offset = *offset;
                 Ok(self.allocator.alloc(*ast.clone()))
             },
@@ -234,7 +297,7 @@ impl TreeInterpreter {
                     })
                 } else {
                     match data.as_array() {
-                        Some(ref array) => {
+                        Some(array) => {
                             Ok(self.allocator.alloc(slice(array, start, stop, step)))
                         },
                         None => Ok(self.allocator.alloc_null())
@@ -243,10 +306,137 @@ impl TreeInterpreter {
             }
         }
     }
+
+    /// Statically validates an AST without any input data.
+    ///
+    /// Walks every `Ast::Function` node and checks that the function name is
+    /// registered and that its argument count and statically-known argument
+    /// types (literals and expression references) match the function's
+    /// declared `Signature`. This catches the same errors `interpret` would
+    /// hit deep inside evaluation, but up front and without requiring input
+    /// data, so callers can validate an expression once at startup rather
+    /// than on live data.
+    ///
+    /// Returns the first failure found, with `Coordinates` pointing at the
+    /// offending node.
+    ///
+    /// This validates against `self.functions` (the same `Functions` registry
+    /// `interpret` calls into), so a function's `signature()` always reflects
+    /// the implementation that will actually run.
+    pub fn validate(&self, node: &Ast, expression: &str) -> Result<(), RuntimeError> {
+        let mut offset = 0usize;
+        self.validate_node(node, expression, &mut offset)
+    }
+
+    fn validate_node(&self, node: &Ast, expression: &str, offset: &mut usize) -> Result<(), RuntimeError> {
+        match node {
+            &Ast::Subexpr { ref lhs, ref rhs, offset: node_offset } => {
+                *offset = node_offset;
+                self.validate_node(lhs, expression, offset)?;
+                self.validate_node(rhs, expression, offset)
+            },
+            &Ast::Or { ref lhs, ref rhs, offset: node_offset }
+            | &Ast::And { ref lhs, ref rhs, offset: node_offset } => {
+                *offset = node_offset;
+                self.validate_node(lhs, expression, offset)?;
+                self.validate_node(rhs, expression, offset)
+            },
+            &Ast::Comparison { ref lhs, ref rhs, offset: node_offset, .. } => {
+                *offset = node_offset;
+                self.validate_node(lhs, expression, offset)?;
+                self.validate_node(rhs, expression, offset)
+            },
+            &Ast::Not { ref node, offset: node_offset } => {
+                *offset = node_offset;
+                self.validate_node(node, expression, offset)
+            },
+            &Ast::Condition { ref predicate, ref then, offset: node_offset } => {
+                *offset = node_offset;
+                self.validate_node(predicate, expression, offset)?;
+                self.validate_node(then, expression, offset)
+            },
+            &Ast::ObjectValues { ref node, offset: node_offset }
+            | &Ast::Flatten { ref node, offset: node_offset } => {
+                *offset = node_offset;
+                self.validate_node(node, expression, offset)
+            },
+            &Ast::Projection { ref lhs, ref rhs, offset: node_offset } => {
+                *offset = node_offset;
+                self.validate_node(lhs, expression, offset)?;
+                self.validate_node(rhs, expression, offset)
+            },
+            &Ast::MultiList { ref elements, offset: node_offset } => {
+                *offset = node_offset;
+                for element in elements {
+                    self.validate_node(element, expression, offset)?;
+                }
+                Ok(())
+            },
+            &Ast::MultiHash { ref elements, offset: node_offset } => {
+                *offset = node_offset;
+                for kvp in elements {
+                    self.validate_node(&kvp.key, expression, offset)?;
+                    self.validate_node(&kvp.value, expression, offset)?;
+                }
+                Ok(())
+            },
+            &Ast::Function { ref name, ref args, offset: node_offset } => {
+                *offset = node_offset;
+                for arg in args {
+                    self.validate_node(arg, expression, offset)?;
+                }
+                *offset = node_offset;
+                match self.functions.get(name) {
+                    None => Err(RuntimeError::UnknownFunction {
+                        coordinates: Coordinates::from_offset(expression, *offset),
+                        expression: expression.to_string(),
+                        function: name.clone()
+                    }),
+                    Some(f) => {
+                        let signature = f.signature();
+                        signature.validate_arity(args.len()).map_err(|(min, max)| {
+                            RuntimeError::InvalidArity {
+                                coordinates: Coordinates::from_offset(expression, *offset),
+                                expression: expression.to_string(),
+                                function: name.clone(),
+                                expected_min: min,
+                                expected_max: max,
+                                actual: args.len(),
+                            }
+                        })?;
+                        for (position, (arg, expected)) in args.iter().zip(signature.argument_types(args.len())).enumerate() {
+                            if let Some(actual) = static_argument_type(arg) {
+                                if !expected.accepts(&actual) {
+                                    return Err(RuntimeError::InvalidArgumentType {
+                                        coordinates: Coordinates::from_offset(expression, *offset),
+                                        expression: expression.to_string(),
+                                        function: name.clone(),
+                                        position,
+                                        expected: expected.to_string(),
+                                        actual: actual.to_string(),
+                                    });
+                                }
+                            }
+                        }
+                        Ok(())
+                    }
+                }
+            },
+            &Ast::Expref { ref ast, offset: node_offset } => {
+                *offset = node_offset;
+                self.validate_node(ast, expression, offset)
+            },
+            &Ast::Field { .. }
+            | &Ast::Identity { .. }
+            | &Ast::Literal { .. }
+            | &Ast::Index { .. }
+            | &Ast::Slice { .. } => Ok(()),
+        }
+    }
 }
 
-fn slice(array: &Vec<RcVar>, start: &Option<i32>, stop: &Option<i32>, step: i32)
-    -> Vec<RcVar>
+fn slice(array: &[Rcvar], start: &Option<i32>, stop: &Option<i32>, step: i32)
+    -> Vec<Rcvar>
 {
     let mut result = vec![];
     let len = array.len() as i32;
@@ -0,0 +1,50 @@
+//! A pure Rust implementation of [JMESPath](https://jmespath.org/), a query
+//! language for JSON.
+//!
+//! ```no_run
+//! use jmespath::Runtime;
+//!
+//! let runtime = Runtime::new();
+//! let expr = runtime.compile("foo.bar").unwrap();
+//! ```
+//!
+//! Built without `std` by default this crate only needs `alloc`; enable the
+//! `std` feature (on by default) for the compiled-expression cache, and the
+//! `regex` feature for the `regex_match`/`regex_replace`/`regex_split`/
+//! `contains_any` builtins.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// `RuntimeError`/`JmespathError` carry rich diagnostic context (coordinates,
+// the original expression, function name, etc.) on every variant so error
+// messages can point at exactly where an expression failed; boxing them to
+// shrink the `Err` side would obscure that context for no real benefit here.
+#![allow(clippy::result_large_err)]
+// `Ast`/`Expression` are built on `Rc`, not `Arc`, for parsing and
+// evaluation (this crate isn't meant to be shared across threads); the
+// compiled-AST cache in `Runtime` uses `Arc` only so cached entries can
+// outlive a single `compile()` call, not for cross-thread sharing.
+#![allow(clippy::arc_with_non_send_sync)]
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+mod ast;
+mod builtins;
+mod errors;
+mod expression;
+mod functions;
+mod interpreter;
+mod parser;
+#[cfg(feature = "regex")]
+mod regex_functions;
+mod runtime;
+mod variable;
+
+pub use ast::{Ast, Comparator, KeyValuePair};
+pub use errors::{Coordinates, JmespathError, ParseError, RuntimeError};
+pub use expression::Expression;
+pub use functions::{ArgumentType, Function, Functions, Signature, StaticArgumentType};
+pub use interpreter::{Context, SearchResult, TreeInterpreter};
+pub use parser::parse;
+pub use runtime::Runtime;
+pub use variable::{Rcvar, Variable, VariableAllocator};
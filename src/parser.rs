@@ -0,0 +1,190 @@
+//! A minimal JMESPath parser.
+//!
+//! This supports the subset of the grammar exercised by this crate's own
+//! functions and tests: `@`, dotted field chains (`a.b.c`), function calls
+//! (`length(@)`, `sort_by(@, &name)`), expression references (`&node`), and
+//! backtick-quoted raw JSON literals (`` `"foo"` ``, `` `42` ``, `` `null` ``).
+//! It does not implement the full grammar (no projections, filters,
+//! comparisons, slices, or multi-select syntax) — those `Ast` variants exist
+//! for the interpreter to walk, but aren't reachable through this parser yet.
+
+#[cfg(feature = "std")]
+use std::rc::Rc;
+#[cfg(not(feature = "std"))]
+use alloc::rc::Rc;
+#[cfg(not(feature = "std"))]
+use alloc::{string::{String, ToString}, vec, vec::Vec, boxed::Box};
+
+use crate::ast::Ast;
+use crate::errors::JmespathError;
+use crate::variable::Variable;
+
+struct Parser<'a> {
+    expression: &'a str,
+    chars: Vec<char>,
+    pos: usize,
+}
+
+/// Parses a JMESPath expression string into an `Ast`.
+pub fn parse(expression: &str) -> Result<Ast, JmespathError> {
+    let mut parser = Parser {
+        expression,
+        chars: expression.chars().collect(),
+        pos: 0,
+    };
+    let ast = parser.parse_expression()?;
+    parser.skip_whitespace();
+    if parser.pos != parser.chars.len() {
+        return Err(parser.error("unexpected trailing input"));
+    }
+    Ok(ast)
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self, message: &str) -> JmespathError {
+        JmespathError::Parse {
+            expression: self.expression.to_string(),
+            offset: self.pos,
+            message: message.to_string(),
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).cloned()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek() {
+            if c.is_whitespace() {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn parse_expression(&mut self) -> Result<Ast, JmespathError> {
+        let mut lhs = self.parse_unary()?;
+        loop {
+            self.skip_whitespace();
+            if self.peek() == Some('.') {
+                let offset = self.pos;
+                self.pos += 1;
+                self.skip_whitespace();
+                let rhs = self.parse_unary()?;
+                lhs = Ast::Subexpr { lhs: Box::new(lhs), rhs: Box::new(rhs), offset };
+            } else {
+                break;
+            }
+        }
+        Ok(lhs)
+    }
+
+    fn parse_unary(&mut self) -> Result<Ast, JmespathError> {
+        self.skip_whitespace();
+        let offset = self.pos;
+        match self.peek() {
+            Some('@') => {
+                self.pos += 1;
+                Ok(Ast::Identity { offset })
+            },
+            Some('&') => {
+                self.pos += 1;
+                let inner = self.parse_unary()?;
+                Ok(Ast::Expref { ast: Box::new(inner), offset })
+            },
+            Some('`') => self.parse_literal(),
+            Some(c) if is_identifier_start(c) => self.parse_field_or_function(),
+            _ => Err(self.error("expected an expression")),
+        }
+    }
+
+    fn parse_field_or_function(&mut self) -> Result<Ast, JmespathError> {
+        let offset = self.pos;
+        let name = self.parse_identifier();
+        self.skip_whitespace();
+        if self.peek() == Some('(') {
+            self.pos += 1;
+            let mut args = vec![];
+            self.skip_whitespace();
+            if self.peek() != Some(')') {
+                loop {
+                    args.push(self.parse_expression()?);
+                    self.skip_whitespace();
+                    if self.peek() == Some(',') {
+                        self.pos += 1;
+                        continue;
+                    }
+                    break;
+                }
+            }
+            self.skip_whitespace();
+            if self.peek() != Some(')') {
+                return Err(self.error("expected ')' to close function call"));
+            }
+            self.pos += 1;
+            Ok(Ast::Function { name, args, offset })
+        } else {
+            Ok(Ast::Field { name, offset })
+        }
+    }
+
+    fn parse_identifier(&mut self) -> String {
+        let start = self.pos;
+        while let Some(c) = self.peek() {
+            if is_identifier_char(c) {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+        self.chars[start..self.pos].iter().collect()
+    }
+
+    fn parse_literal(&mut self) -> Result<Ast, JmespathError> {
+        let offset = self.pos;
+        self.pos += 1; // consume opening '`'
+        let start = self.pos;
+        while let Some(c) = self.peek() {
+            if c == '`' {
+                break;
+            }
+            self.pos += 1;
+        }
+        if self.peek() != Some('`') {
+            return Err(self.error("unterminated raw string literal"));
+        }
+        let raw: String = self.chars[start..self.pos].iter().collect();
+        self.pos += 1; // consume closing '`'
+        let value = parse_json_literal(&raw).ok_or_else(|| self.error("invalid JSON literal"))?;
+        Ok(Ast::Literal { value: Rc::new(value), offset })
+    }
+}
+
+fn is_identifier_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_'
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Parses the handful of JSON literal shapes backtick literals need:
+/// `null`, `true`, `false`, numbers, and double-quoted strings. Arrays and
+/// objects are not supported yet.
+fn parse_json_literal(raw: &str) -> Option<Variable> {
+    let trimmed = raw.trim();
+    match trimmed {
+        "null" => return Some(Variable::Null),
+        "true" => return Some(Variable::Bool(true)),
+        "false" => return Some(Variable::Bool(false)),
+        _ => {},
+    }
+    if let Ok(n) = trimmed.parse::<f64>() {
+        return Some(Variable::Number(n));
+    }
+    if trimmed.starts_with('"') && trimmed.ends_with('"') && trimmed.len() >= 2 {
+        return Some(Variable::String(trimmed[1..trimmed.len() - 1].to_string()));
+    }
+    None
+}
@@ -0,0 +1,206 @@
+//! Regex and multi-pattern string matching functions.
+//!
+//! These are kept out of [`register_builtin_functions`](crate::Runtime::register_builtin_functions)
+//! and behind the `regex` feature so that consumers who don't need pattern
+//! matching aren't forced to pull in `regex`/`aho-corasick`. Call
+//! [`Runtime::register_regex_functions`] explicitly to make them available.
+//!
+//! The `regex` feature depends on `std` (the cache fields below use
+//! `std::sync::Mutex`, and the `regex`/`aho-corasick` crates themselves
+//! assume it), so `std::collections`/`std::sync` are used unconditionally
+//! here rather than gated on a `std` feature check.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use aho_corasick::AhoCorasick;
+use regex::Regex;
+
+use crate::interpreter::Context;
+use crate::errors::RuntimeError;
+use crate::functions::{ArgumentType, Function, Signature};
+use crate::variable::{Rcvar, Variable};
+
+/// Compiles and caches a `Regex` for a literal pattern argument so that
+/// repeated evaluations inside a projection don't recompile it.
+fn cached_regex(cache: &Mutex<HashMap<String, Regex>>, pattern: &str, ctx: &mut Context) -> Result<Regex, RuntimeError> {
+    let mut cache = cache.lock().unwrap();
+    if let Some(re) = cache.get(pattern) {
+        return Ok(re.clone());
+    }
+    let re = Regex::new(pattern).map_err(|e| RuntimeError::InvalidArgument {
+        coordinates: ctx.create_coordinates(),
+        expression: ctx.expression.to_string(),
+        message: e.to_string(),
+    })?;
+    cache.insert(pattern.to_owned(), re.clone());
+    Ok(re)
+}
+
+/// Guards against the out-of-bounds `args[n]` panics that a wrong-arity call
+/// (e.g. `regex_match(@)`) would otherwise hit, returning the same kind of
+/// error the core builtins report for their own arity mismatches.
+fn expect_arity(name: &str, args: &[Rcvar], expected: usize, ctx: &mut Context) -> Result<(), RuntimeError> {
+    if args.len() == expected {
+        Ok(())
+    } else {
+        Err(RuntimeError::InvalidArity {
+            coordinates: ctx.create_coordinates(),
+            expression: ctx.expression.to_string(),
+            function: name.to_string(),
+            expected_min: expected,
+            expected_max: expected,
+            actual: args.len(),
+        })
+    }
+}
+
+fn expect_string(value: &Rcvar, ctx: &mut Context) -> Result<String, RuntimeError> {
+    match **value {
+        Variable::String(ref s) => Ok(s.clone()),
+        ref other => Err(RuntimeError::InvalidType {
+            coordinates: ctx.create_coordinates(),
+            expression: ctx.expression.to_string(),
+            expected: "string".to_string(),
+            actual: other.get_type().to_string(),
+        }),
+    }
+}
+
+/// `regex_match(subject: string, pattern: string) -> boolean`
+#[derive(Debug, Default)]
+pub struct RegexMatchFn {
+    cache: Mutex<HashMap<String, Regex>>,
+}
+
+impl RegexMatchFn {
+    pub fn new() -> RegexMatchFn {
+        Default::default()
+    }
+}
+
+impl Function for RegexMatchFn {
+    fn signature(&self) -> Signature {
+        Signature::new(vec![ArgumentType::String, ArgumentType::String])
+    }
+
+    fn evaluate(&self, args: &[Rcvar], ctx: &mut Context) -> Result<Rcvar, RuntimeError> {
+        expect_arity("regex_match", args, 2, ctx)?;
+        let subject = expect_string(&args[0], ctx)?;
+        let pattern = expect_string(&args[1], ctx)?;
+        let re = cached_regex(&self.cache, &pattern, ctx)?;
+        Ok(ctx.interpreter.allocator.alloc_bool(re.is_match(&subject)))
+    }
+}
+
+/// `regex_replace(subject: string, pattern: string, replacement: string) -> string`
+#[derive(Debug, Default)]
+pub struct RegexReplaceFn {
+    cache: Mutex<HashMap<String, Regex>>,
+}
+
+impl RegexReplaceFn {
+    pub fn new() -> RegexReplaceFn {
+        Default::default()
+    }
+}
+
+impl Function for RegexReplaceFn {
+    fn signature(&self) -> Signature {
+        Signature::new(vec![ArgumentType::String, ArgumentType::String, ArgumentType::String])
+    }
+
+    fn evaluate(&self, args: &[Rcvar], ctx: &mut Context) -> Result<Rcvar, RuntimeError> {
+        expect_arity("regex_replace", args, 3, ctx)?;
+        let subject = expect_string(&args[0], ctx)?;
+        let pattern = expect_string(&args[1], ctx)?;
+        let replacement = expect_string(&args[2], ctx)?;
+        let re = cached_regex(&self.cache, &pattern, ctx)?;
+        let result = re.replace_all(&subject, replacement.as_str()).into_owned();
+        Ok(ctx.interpreter.allocator.alloc(Variable::String(result)))
+    }
+}
+
+/// `regex_split(subject: string, pattern: string) -> array[string]`
+#[derive(Debug, Default)]
+pub struct RegexSplitFn {
+    cache: Mutex<HashMap<String, Regex>>,
+}
+
+impl RegexSplitFn {
+    pub fn new() -> RegexSplitFn {
+        Default::default()
+    }
+}
+
+impl Function for RegexSplitFn {
+    fn signature(&self) -> Signature {
+        Signature::new(vec![ArgumentType::String, ArgumentType::String])
+    }
+
+    fn evaluate(&self, args: &[Rcvar], ctx: &mut Context) -> Result<Rcvar, RuntimeError> {
+        expect_arity("regex_split", args, 2, ctx)?;
+        let subject = expect_string(&args[0], ctx)?;
+        let pattern = expect_string(&args[1], ctx)?;
+        let re = cached_regex(&self.cache, &pattern, ctx)?;
+        let parts = re.split(&subject)
+            .map(|part| ctx.interpreter.allocator.alloc(Variable::String(part.to_string())))
+            .collect::<Vec<Rcvar>>();
+        Ok(ctx.interpreter.allocator.alloc(Variable::Array(parts)))
+    }
+}
+
+/// `contains_any(subject: string, needles: array[string]) -> boolean`
+///
+/// Uses an Aho-Corasick automaton so that matching many literal needles
+/// against one subject stays linear in the subject length.
+#[derive(Debug, Default)]
+pub struct ContainsAnyFn {
+    cache: Mutex<HashMap<Vec<String>, AhoCorasick>>,
+}
+
+impl ContainsAnyFn {
+    pub fn new() -> ContainsAnyFn {
+        Default::default()
+    }
+}
+
+impl Function for ContainsAnyFn {
+    fn signature(&self) -> Signature {
+        Signature::new(vec![ArgumentType::String, ArgumentType::Array])
+    }
+
+    fn evaluate(&self, args: &[Rcvar], ctx: &mut Context) -> Result<Rcvar, RuntimeError> {
+        expect_arity("contains_any", args, 2, ctx)?;
+        let subject = expect_string(&args[0], ctx)?;
+        let needles = match *args[1] {
+            Variable::Array(ref values) => values
+                .iter()
+                .map(|v| expect_string(v, ctx))
+                .collect::<Result<Vec<String>, RuntimeError>>()?,
+            ref other => {
+                return Err(RuntimeError::InvalidType {
+                    coordinates: ctx.create_coordinates(),
+                    expression: ctx.expression.to_string(),
+                    expected: "array[string]".to_string(),
+                    actual: other.get_type().to_string(),
+                })
+            }
+        };
+
+        let mut cache = self.cache.lock().unwrap();
+        let is_match = if let Some(automaton) = cache.get(&needles) {
+            automaton.is_match(&subject)
+        } else {
+            let automaton = AhoCorasick::new(&needles).map_err(|e| RuntimeError::InvalidArgument {
+                coordinates: ctx.create_coordinates(),
+                expression: ctx.expression.to_string(),
+                message: e.to_string(),
+            })?;
+            let is_match = automaton.is_match(&subject);
+            cache.insert(needles, automaton);
+            is_match
+        };
+        Ok(ctx.interpreter.allocator.alloc_bool(is_match))
+    }
+}
@@ -0,0 +1,132 @@
+//! Compiles JMESPath expression strings into [`Expression`]s.
+
+#[cfg(feature = "std")]
+use std::num::NonZeroUsize;
+#[cfg(feature = "std")]
+use std::sync::Mutex;
+#[cfg(feature = "std")]
+use std::sync::Arc;
+#[cfg(not(feature = "std"))]
+use alloc::sync::Arc;
+
+#[cfg(feature = "std")]
+use lru::LruCache;
+
+#[cfg(feature = "std")]
+use super::ast::Ast;
+use super::errors::JmespathError;
+use super::expression::Expression;
+use super::functions::Function;
+use super::interpreter::TreeInterpreter;
+use super::parser::parse;
+
+/// Compiles JMESPath expressions.
+///
+/// Most use cases don't need to worry about how Runtime works. You really
+/// only need to create your own Runtimes if you are utilizing custom
+/// functions in your expressions.
+#[derive(Default)]
+pub struct Runtime {
+    pub(crate) interpreter: TreeInterpreter,
+    // `None` means caching is disabled (the default), matching the
+    // re-parse-every-time behavior of `Runtime::new`. Only available with
+    // the `std` feature, since the cache is built on `std::sync::Mutex`.
+    #[cfg(feature = "std")]
+    cache: Option<Mutex<LruCache<String, Arc<Ast>>>>,
+}
+
+impl Runtime {
+    /// Creates a new Runtime with the core builtin functions registered.
+    pub fn new() -> Runtime {
+        Default::default()
+    }
+
+    /// Creates a new Runtime with a bounded LRU cache of compiled ASTs.
+    ///
+    /// `capacity` is the maximum number of distinct expression strings to
+    /// keep parsed ASTs for; a capacity of `0` disables the cache, which is
+    /// identical to [`Runtime::new`]. Requires the `std` feature.
+    #[cfg(feature = "std")]
+    pub fn with_cache(capacity: usize) -> Runtime {
+        let mut runtime = Runtime::new();
+        if let Some(capacity) = NonZeroUsize::new(capacity) {
+            runtime.cache = Some(Mutex::new(LruCache::new(capacity)));
+        }
+        runtime
+    }
+
+    /// Creates a new JMESPath expression from an expression string.
+    ///
+    /// The provided expression is expected to adhere to the JMESPath
+    /// grammar: <https://jmespath.org/specification.html>
+    ///
+    /// When the runtime was created with [`Runtime::with_cache`], repeated
+    /// calls with the same `expression` string skip re-parsing and reuse the
+    /// previously compiled `Ast`.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn compile<'a>(&'a self, expression: &str) -> Result<Expression<'a>, JmespathError> {
+        let cache = match self.cache {
+            Some(ref cache) => cache,
+            None => return parse(expression).map(|ast| Expression::new(expression, Arc::new(ast), self)),
+        };
+        let mut cache = cache.lock().unwrap();
+        if let Some(ast) = cache.get(expression) {
+            return Ok(Expression::new(expression, ast.clone(), self));
+        }
+        let ast = Arc::new(parse(expression)?);
+        cache.put(expression.to_owned(), ast.clone());
+        Ok(Expression::new(expression, ast, self))
+    }
+
+    /// Creates a new JMESPath expression from an expression string.
+    ///
+    /// `no_std` builds don't have the AST cache, so this always parses.
+    #[cfg(not(feature = "std"))]
+    #[inline]
+    pub fn compile<'a>(&'a self, expression: &str) -> Result<Expression<'a>, JmespathError> {
+        parse(expression).map(|ast| Expression::new(expression, Arc::new(ast), self))
+    }
+
+    /// Adds a new function to the runtime.
+    #[inline]
+    pub fn register_function(&mut self, name: &str, f: Arc<dyn Function>) {
+        self.interpreter.register_function(name, f);
+    }
+
+    /// Removes a function from the runtime.
+    ///
+    /// Returns the function that was removed if it was found.
+    pub fn deregister_function(&mut self, name: &str) -> Option<Arc<dyn Function>> {
+        self.interpreter.deregister_function(name)
+    }
+
+    /// Gets a function by name from the runtime.
+    pub fn get_function<'a>(&'a self, name: &str) -> Option<&'a dyn Function> {
+        self.interpreter.get_function(name).map(AsRef::as_ref)
+    }
+
+    /// Registers all of the builtin JMESPath functions with the runtime.
+    ///
+    /// `Runtime::new` already does this; this exists for runtimes that were
+    /// built up some other way and want the core builtins layered in.
+    pub fn register_builtin_functions(&mut self) {
+        self.interpreter.register_builtins();
+    }
+
+    /// Registers the `regex`-backed string functions (`regex_match`,
+    /// `regex_replace`, `regex_split`, and `contains_any`) with the runtime.
+    ///
+    /// Kept separate from [`Runtime::register_builtin_functions`] behind the
+    /// `regex` feature so that the core crate stays dependency-light for
+    /// consumers who don't need pattern matching.
+    #[cfg(feature = "regex")]
+    pub fn register_regex_functions(&mut self) {
+        use super::regex_functions::{ContainsAnyFn, RegexMatchFn, RegexReplaceFn, RegexSplitFn};
+
+        self.register_function("regex_match", Arc::new(RegexMatchFn::new()));
+        self.register_function("regex_replace", Arc::new(RegexReplaceFn::new()));
+        self.register_function("regex_split", Arc::new(RegexSplitFn::new()));
+        self.register_function("contains_any", Arc::new(ContainsAnyFn::new()));
+    }
+}
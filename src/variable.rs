@@ -0,0 +1,183 @@
+//! Runtime representation of JSON-like values produced while interpreting
+//! JMESPath expressions.
+
+#[cfg(feature = "std")]
+use std::collections::BTreeMap;
+#[cfg(not(feature = "std"))]
+use alloc::collections::BTreeMap;
+#[cfg(feature = "std")]
+use std::rc::Rc;
+#[cfg(not(feature = "std"))]
+use alloc::rc::Rc;
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::ast::{Ast, Comparator};
+
+/// Reference-counted JMESPath runtime value.
+pub type Rcvar = Rc<Variable>;
+
+/// A JMESPath runtime value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Variable {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Rcvar>),
+    Object(BTreeMap<String, Rcvar>),
+    /// An unevaluated expression reference, produced by `Ast::Expref` and
+    /// consumed by functions like `sort_by`/`max_by`.
+    Expref(Ast),
+}
+
+impl Variable {
+    pub fn get_type(&self) -> &'static str {
+        match *self {
+            Variable::Null => "null",
+            Variable::Bool(_) => "boolean",
+            Variable::Number(_) => "number",
+            Variable::String(_) => "string",
+            Variable::Array(_) => "array",
+            Variable::Object(_) => "object",
+            Variable::Expref(_) => "expref",
+        }
+    }
+
+    pub fn is_null(&self) -> bool {
+        matches!(*self, Variable::Null)
+    }
+
+    pub fn is_truthy(&self) -> bool {
+        match *self {
+            Variable::Null => false,
+            Variable::Bool(b) => b,
+            Variable::String(ref s) => !s.is_empty(),
+            Variable::Array(ref a) => !a.is_empty(),
+            Variable::Object(ref o) => !o.is_empty(),
+            Variable::Number(_) | Variable::Expref(_) => true,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&Vec<Rcvar>> {
+        match *self {
+            Variable::Array(ref a) => Some(a),
+            _ => None,
+        }
+    }
+
+    pub fn get_value(&self, key: &str) -> Option<Rcvar> {
+        match *self {
+            Variable::Object(ref map) => map.get(key).cloned(),
+            _ => None,
+        }
+    }
+
+    pub fn get_index(&self, index: usize) -> Option<Rcvar> {
+        match *self {
+            Variable::Array(ref a) => a.get(index).cloned(),
+            _ => None,
+        }
+    }
+
+    pub fn get_negative_index(&self, index_from_end: usize) -> Option<Rcvar> {
+        match *self {
+            Variable::Array(ref a) => {
+                if index_from_end == 0 || index_from_end > a.len() {
+                    None
+                } else {
+                    a.get(a.len() - index_from_end).cloned()
+                }
+            },
+            _ => None,
+        }
+    }
+
+    pub fn compare(&self, cmp: &Comparator, other: &Variable) -> Option<bool> {
+        match *cmp {
+            Comparator::Eq => Some(self == other),
+            Comparator::Ne => Some(self != other),
+            Comparator::Lt | Comparator::Lte | Comparator::Gt | Comparator::Gte => {
+                match (self, other) {
+                    (&Variable::Number(a), &Variable::Number(b)) => Some(match *cmp {
+                        Comparator::Lt => a < b,
+                        Comparator::Lte => a <= b,
+                        Comparator::Gt => a > b,
+                        Comparator::Gte => a >= b,
+                        Comparator::Eq | Comparator::Ne => unreachable!(),
+                    }),
+                    _ => None,
+                }
+            }
+        }
+    }
+}
+
+impl From<Vec<Rcvar>> for Variable {
+    fn from(v: Vec<Rcvar>) -> Variable {
+        Variable::Array(v)
+    }
+}
+
+impl From<BTreeMap<String, Rcvar>> for Variable {
+    fn from(v: BTreeMap<String, Rcvar>) -> Variable {
+        Variable::Object(v)
+    }
+}
+
+impl From<Ast> for Variable {
+    fn from(ast: Ast) -> Variable {
+        Variable::Expref(ast)
+    }
+}
+
+/// Allocates `Rcvar`s while interpreting an expression.
+///
+/// `null`, `true`, and `false` are immutable and requested constantly (every
+/// missing field access allocates a null, every comparison a bool), so this
+/// caches one shared `Rcvar` for each and hands out clones instead of
+/// allocating a fresh `Rc` on every call.
+pub struct VariableAllocator {
+    null: Rcvar,
+    cached_true: Rcvar,
+    cached_false: Rcvar,
+}
+
+impl Default for VariableAllocator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl VariableAllocator {
+    pub fn new() -> VariableAllocator {
+        VariableAllocator {
+            null: Rc::new(Variable::Null),
+            cached_true: Rc::new(Variable::Bool(true)),
+            cached_false: Rc::new(Variable::Bool(false)),
+        }
+    }
+
+    /// Returns the shared `null` singleton.
+    #[inline]
+    pub fn alloc_null(&self) -> Rcvar {
+        self.null.clone()
+    }
+
+    /// Returns the shared `true`/`false` singleton for `value`.
+    #[inline]
+    pub fn alloc_bool(&self, value: bool) -> Rcvar {
+        if value {
+            self.cached_true.clone()
+        } else {
+            self.cached_false.clone()
+        }
+    }
+
+    /// Allocates a new variable from any type that converts into `Variable`.
+    pub fn alloc<T: Into<Variable>>(&self, value: T) -> Rcvar {
+        Rc::new(value.into())
+    }
+}